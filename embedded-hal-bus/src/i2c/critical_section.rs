@@ -0,0 +1,78 @@
+use core::cell::RefCell;
+use critical_section::Mutex;
+use embedded_hal::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+
+/// `critical-section`-based shared bus [`I2c`] implementation.
+///
+/// Sharing is implemented with a `critical-section` [`Mutex`], so it's `Send`
+/// and can be shared between threads or interrupt priority levels. Each access
+/// borrows the bus inside a critical section for the duration of the call, which
+/// briefly disables interrupts.
+///
+/// The bus errors are surfaced directly as this device's error type: unlike the
+/// SPI devices there is no `CS` pin to fold in, so no `DeviceError` wrapper is
+/// needed.
+pub struct CriticalSectionDevice<'a, BUS> {
+    bus: &'a Mutex<RefCell<BUS>>,
+}
+
+impl<'a, BUS> CriticalSectionDevice<'a, BUS> {
+    /// Create a new [`CriticalSectionDevice`].
+    #[inline]
+    pub fn new(bus: &'a Mutex<RefCell<BUS>>) -> Self {
+        Self { bus }
+    }
+}
+
+impl<'a, BUS> ErrorType for CriticalSectionDevice<'a, BUS>
+where
+    BUS: ErrorType,
+{
+    type Error = BUS::Error;
+}
+
+impl<'a, BUS> I2c for CriticalSectionDevice<'a, BUS>
+where
+    BUS: I2c,
+{
+    #[inline]
+    fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        critical_section::with(|cs| {
+            let bus = &mut *self.bus.borrow_ref_mut(cs);
+            bus.read(address, read)
+        })
+    }
+
+    #[inline]
+    fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        critical_section::with(|cs| {
+            let bus = &mut *self.bus.borrow_ref_mut(cs);
+            bus.write(address, write)
+        })
+    }
+
+    #[inline]
+    fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        critical_section::with(|cs| {
+            let bus = &mut *self.bus.borrow_ref_mut(cs);
+            bus.write_read(address, write, read)
+        })
+    }
+
+    #[inline]
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        critical_section::with(|cs| {
+            let bus = &mut *self.bus.borrow_ref_mut(cs);
+            bus.transaction(address, operations)
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use core::cell::RefCell;
+use embedded_hal::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+
+/// `RefCell`-based shared bus [`I2c`] implementation.
+///
+/// This allows for sharing an [`I2c`], obtaining multiple [`I2c`] instances
+/// backed by the same physical bus.
+///
+/// Sharing is implemented with a `RefCell`. This means it has low overhead, but `RefCellDevice` instances are not `Send`,
+/// so it only allows sharing within a single thread (interrupt priority level). If you need to share a bus across several
+/// threads, use [`CriticalSectionDevice`](super::CriticalSectionDevice) instead.
+pub struct RefCellDevice<'a, BUS> {
+    bus: &'a RefCell<BUS>,
+}
+
+impl<'a, BUS> RefCellDevice<'a, BUS> {
+    /// Create a new [`RefCellDevice`].
+    #[inline]
+    pub fn new(bus: &'a RefCell<BUS>) -> Self {
+        Self { bus }
+    }
+}
+
+// The bus error is surfaced directly rather than through a `DeviceError`-style
+// wrapper: unlike SPI there is no `CS` error to fold in, so wrapping would only
+// add a pointless single-variant enum. This matches the upstream I2C devices.
+impl<'a, BUS> ErrorType for RefCellDevice<'a, BUS>
+where
+    BUS: ErrorType,
+{
+    type Error = BUS::Error;
+}
+
+impl<'a, BUS> I2c for RefCellDevice<'a, BUS>
+where
+    BUS: I2c,
+{
+    #[inline]
+    fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        let bus = &mut *self.bus.borrow_mut();
+        bus.read(address, read)
+    }
+
+    #[inline]
+    fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        let bus = &mut *self.bus.borrow_mut();
+        bus.write(address, write)
+    }
+
+    #[inline]
+    fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let bus = &mut *self.bus.borrow_mut();
+        bus.write_read(address, write, read)
+    }
+
+    #[inline]
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let bus = &mut *self.bus.borrow_mut();
+        bus.transaction(address, operations)
+    }
+}
@@ -0,0 +1,110 @@
+use core::ops::RangeInclusive;
+
+use embedded_hal::spi::{ErrorType, SpiBus};
+
+/// DMA-safe write buffering [`SpiBus`] wrapper.
+///
+/// Controllers backed by EasyDMA can only transmit from RAM, so write sources
+/// that live in flash (string literals, `const` tables) fail at runtime. For
+/// each write-side slice, `CopyToRamDevice` checks whether the slice lies inside
+/// the supplied RAM address range and, if not, bounces it through a fixed
+/// `[Word; N]` scratch buffer in `N`-word chunks before handing each chunk to
+/// the inner bus. Read-side buffers are assumed to already be in RAM and pass
+/// through unchanged.
+///
+/// This lets generic drivers transmit `const` data on DMA-only controllers
+/// without every caller manually bouncing buffers.
+pub struct CopyToRamDevice<BUS, const N: usize> {
+    bus: BUS,
+    ram: RangeInclusive<usize>,
+}
+
+impl<BUS, const N: usize> CopyToRamDevice<BUS, N> {
+    /// Create a new [`CopyToRamDevice`].
+    ///
+    /// `ram` is the inclusive range of addresses the controller's DMA engine can
+    /// read from; write slices whose bytes all fall inside it are forwarded
+    /// directly, the rest are bounced through the scratch buffer.
+    #[inline]
+    pub fn new(bus: BUS, ram: RangeInclusive<usize>) -> Self {
+        Self { bus, ram }
+    }
+
+    /// Consume the wrapper, returning the inner bus.
+    #[inline]
+    pub fn into_inner(self) -> BUS {
+        self.bus
+    }
+
+    fn in_ram<Word>(&self, slice: &[Word]) -> bool {
+        if slice.is_empty() {
+            return true;
+        }
+        let start = slice.as_ptr() as usize;
+        // `len` is in words; the last addressable byte is `end - 1`.
+        let end = start + core::mem::size_of_val(slice);
+        self.ram.contains(&start) && self.ram.contains(&(end - 1))
+    }
+}
+
+impl<BUS: ErrorType, const N: usize> ErrorType for CopyToRamDevice<BUS, N> {
+    type Error = BUS::Error;
+}
+
+impl<Word: Copy + Default + 'static, BUS, const N: usize> SpiBus<Word>
+    for CopyToRamDevice<BUS, N>
+where
+    BUS: SpiBus<Word>,
+{
+    #[inline]
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        self.bus.read(words)
+    }
+
+    #[inline]
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        if self.in_ram(words) {
+            return self.bus.write(words);
+        }
+        let mut scratch = [Word::default(); N];
+        for chunk in words.chunks(N) {
+            scratch[..chunk.len()].copy_from_slice(chunk);
+            self.bus.write(&scratch[..chunk.len()])?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        if self.in_ram(write) {
+            return self.bus.transfer(read, write);
+        }
+        // Bounce the write side through the scratch buffer while advancing the
+        // read side in lockstep, preserving the discard/filler semantics of
+        // `SpiBus::transfer`.
+        let mut scratch = [Word::default(); N];
+        let mut read = read;
+        let mut write = write;
+        while !read.is_empty() || !write.is_empty() {
+            let n = N.min(write.len());
+            let (read_chunk, read_rest) = read.split_at_mut(N.min(read.len()));
+            let (write_chunk, write_rest) = write.split_at(n);
+            scratch[..n].copy_from_slice(write_chunk);
+            self.bus.transfer(read_chunk, &scratch[..n])?;
+            read = read_rest;
+            write = write_rest;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        // A `&mut` buffer is necessarily in RAM already.
+        self.bus.transfer_in_place(words)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.bus.flush()
+    }
+}
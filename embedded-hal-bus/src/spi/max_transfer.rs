@@ -0,0 +1,91 @@
+use embedded_hal::spi::{ErrorType, SpiBus};
+
+/// Transfer-length chunking [`SpiBus`] wrapper.
+///
+/// Many SPI controllers (esp-idf's `trans_len`, nRF EasyDMA) can only move a
+/// bounded number of words per hardware transaction. `MaxTransferDevice` splits
+/// every bus call into sub-chunks of at most `max_len` words and issues them
+/// sequentially on the inner bus, so a driver written against the full-length
+/// API runs unchanged on a controller with a small DMA descriptor limit.
+///
+/// Chunking happens below the shared-bus layer: wrap the raw bus in a
+/// `MaxTransferDevice` and hand that to a [`RefCellDevice`](super::RefCellDevice).
+/// CS is owned by the shared-bus device and is never toggled between chunks.
+pub struct MaxTransferDevice<BUS> {
+    bus: BUS,
+    max_len: usize,
+}
+
+impl<BUS> MaxTransferDevice<BUS> {
+    /// Create a new [`MaxTransferDevice`] splitting transfers at `max_len` words.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is zero, as no progress could be made.
+    #[inline]
+    pub fn new(bus: BUS, max_len: usize) -> Self {
+        assert!(max_len > 0, "max_len must be greater than zero");
+        Self { bus, max_len }
+    }
+
+    /// Consume the wrapper, returning the inner bus.
+    #[inline]
+    pub fn into_inner(self) -> BUS {
+        self.bus
+    }
+}
+
+impl<BUS: ErrorType> ErrorType for MaxTransferDevice<BUS> {
+    type Error = BUS::Error;
+}
+
+impl<Word: Copy + 'static, BUS> SpiBus<Word> for MaxTransferDevice<BUS>
+where
+    BUS: SpiBus<Word>,
+{
+    #[inline]
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        for chunk in words.chunks_mut(self.max_len) {
+            self.bus.read(chunk)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        for chunk in words.chunks(self.max_len) {
+            self.bus.write(chunk)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        // Advance both slices in lockstep up to `max(read.len(), write.len())`,
+        // feeding empty sub-slices once one side is exhausted so the
+        // discard/filler semantics documented on `SpiBus::transfer` are preserved.
+        let mut read = read;
+        let mut write = write;
+        while !read.is_empty() || !write.is_empty() {
+            let (read_chunk, read_rest) = read.split_at_mut(self.max_len.min(read.len()));
+            let (write_chunk, write_rest) = write.split_at(self.max_len.min(write.len()));
+            self.bus.transfer(read_chunk, write_chunk)?;
+            read = read_rest;
+            write = write_rest;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        for chunk in words.chunks_mut(self.max_len) {
+            self.bus.transfer_in_place(chunk)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.bus.flush()
+    }
+}
@@ -1,10 +1,15 @@
 use core::cell::RefCell;
 use embedded_hal::delay::DelayUs;
-use embedded_hal::digital::OutputPin;
+use embedded_hal::digital::{OutputPin, PinState};
 use embedded_hal::spi::{ErrorType, Operation, SpiBus, SpiDevice};
 
 use super::DeviceError;
 
+#[cfg(feature = "async")]
+use embedded_hal_async::{
+    delay::DelayNs as AsyncDelayNs, spi::SpiBus as AsyncSpiBus, spi::SpiDevice as AsyncSpiDevice,
+};
+
 /// `RefCell`-based shared bus [`SpiDevice`] implementation.
 ///
 /// This allows for sharing an [`SpiBus`], obtaining multiple [`SpiDevice`] instances,
@@ -17,13 +22,70 @@ pub struct RefCellDevice<'a, BUS, CS, D> {
     bus: &'a RefCell<BUS>,
     cs: CS,
     delay: D,
+    /// CS is asserted by driving the pin high instead of low.
+    cs_active_high: bool,
+    /// If set, CS is pulsed (deasserted and reasserted) between operations,
+    /// waiting this many microseconds after deasserting before reasserting.
+    cs_pulse_us: Option<u32>,
 }
 
 impl<'a, BUS, CS, D> RefCellDevice<'a, BUS, CS, D> {
     /// Create a new [`RefCellDevice`].
+    ///
+    /// CS defaults to active-low and is held asserted for the whole transaction.
+    /// Use [`with_cs_active_high`](Self::with_cs_active_high) and
+    /// [`with_cs_toggle`](Self::with_cs_toggle) to change this.
     #[inline]
     pub fn new(bus: &'a RefCell<BUS>, cs: CS, delay: D) -> Self {
-        Self { bus, cs, delay }
+        Self {
+            bus,
+            cs,
+            delay,
+            cs_active_high: false,
+            cs_pulse_us: None,
+        }
+    }
+
+    /// Configure CS as active-high, inverting the asserted and deasserted levels.
+    ///
+    /// With this set, CS is driven high to select the device and low to
+    /// deselect it.
+    #[inline]
+    pub fn with_cs_active_high(mut self) -> Self {
+        self.cs_active_high = true;
+        self
+    }
+
+    /// Pulse CS between operations within a transaction.
+    ///
+    /// CS is deasserted and reasserted around each [`Operation`] boundary,
+    /// waiting `settle_us` microseconds (via the device's [`DelayUs`]) after
+    /// deasserting before reasserting. This suits peripherals that latch on CS
+    /// edges or require CS high between command and response phases.
+    #[inline]
+    pub fn with_cs_toggle(mut self, settle_us: u32) -> Self {
+        self.cs_pulse_us = Some(settle_us);
+        self
+    }
+
+    /// Level the CS pin is driven to when the device is selected.
+    #[inline]
+    fn cs_asserted(&self) -> PinState {
+        if self.cs_active_high {
+            PinState::High
+        } else {
+            PinState::Low
+        }
+    }
+
+    /// Level the CS pin is driven to when the device is deselected.
+    #[inline]
+    fn cs_deasserted(&self) -> PinState {
+        if self.cs_active_high {
+            PinState::Low
+        } else {
+            PinState::High
+        }
     }
 }
 
@@ -40,6 +102,8 @@ impl<'a, BUS, CS> RefCellDevice<'a, BUS, CS, super::NoDelay> {
             bus,
             cs,
             delay: super::NoDelay,
+            cs_active_high: false,
+            cs_pulse_us: None,
         }
     }
 }
@@ -61,26 +125,128 @@ where
     #[inline]
     fn transaction(&mut self, operations: &mut [Operation<'_, Word>]) -> Result<(), Self::Error> {
         let bus = &mut *self.bus.borrow_mut();
+        let asserted = self.cs_asserted();
+        let deasserted = self.cs_deasserted();
 
-        self.cs.set_low().map_err(DeviceError::Cs)?;
-
-        let op_res = operations.iter_mut().try_for_each(|op| match op {
-            Operation::Read(buf) => bus.read(buf),
-            Operation::Write(buf) => bus.write(buf),
-            Operation::Transfer(read, write) => bus.transfer(read, write),
-            Operation::TransferInPlace(buf) => bus.transfer_in_place(buf),
-            Operation::DelayUs(us) => {
-                bus.flush()?;
-                self.delay.delay_us(*us);
-                Ok(())
+        self.cs.set_state(asserted).map_err(DeviceError::Cs)?;
+
+        let op_res = operations.iter_mut().enumerate().try_for_each(|(i, op)| {
+            // Pulse CS around each operation boundary if requested. The bus must
+            // be flushed before CS moves so the words already belong to the
+            // previous selection.
+            if i != 0 {
+                if let Some(settle_us) = self.cs_pulse_us {
+                    bus.flush().map_err(DeviceError::Spi)?;
+                    self.cs.set_state(deasserted).map_err(DeviceError::Cs)?;
+                    self.delay.delay_us(settle_us);
+                    self.cs.set_state(asserted).map_err(DeviceError::Cs)?;
+                }
+            }
+            match op {
+                Operation::Read(buf) => bus.read(buf),
+                Operation::Write(buf) => bus.write(buf),
+                Operation::Transfer(read, write) => bus.transfer(read, write),
+                Operation::TransferInPlace(buf) => bus.transfer_in_place(buf),
+                Operation::DelayUs(us) => {
+                    bus.flush().map_err(DeviceError::Spi)?;
+                    self.delay.delay_us(*us);
+                    Ok(())
+                }
             }
+            .map_err(DeviceError::Spi)
         });
 
         // On failure, it's important to still flush and deassert CS.
         let flush_res = bus.flush();
-        let cs_res = self.cs.set_high();
+        let cs_res = self.cs.set_state(deasserted);
+
+        op_res?;
+        flush_res.map_err(DeviceError::Spi)?;
+        cs_res.map_err(DeviceError::Cs)?;
+
+        Ok(())
+    }
+}
+
+// NOTE: `Operation::WriteRepeated(word, count)` (chunk0-4) is intentionally not
+// handled here. It is not a variant of `embedded_hal::spi::Operation`, and a
+// downstream crate like `embedded-hal-bus` cannot add one to that foreign enum.
+// Implementing it requires first adding the variant to the `Operation`
+// definition in the `embedded-hal` crate itself — a coordinated change to that
+// crate — after which a `write_repeated` arm streaming the word through a small
+// fixed-size stack buffer can be matched here. That enum is not part of this
+// snapshot, so the feature is deferred to that upstream change.
+
+// NOTE: Only `RefCellDevice` gets an async `SpiDevice`. Its sibling
+// `CriticalSectionDevice` can't: its transaction holds the bus through a
+// `critical_section::with` closure, and a critical section cannot be held across
+// an `.await` point. Async drivers that need to share a bus across executors
+// should use `RefCellDevice` within a single executor task (as embassy's SPIM
+// sharing does) rather than a critical-section device.
+#[cfg(feature = "async")]
+impl<'a, Word: Copy + 'static, BUS, CS, D> AsyncSpiDevice<Word> for RefCellDevice<'a, BUS, CS, D>
+where
+    BUS: AsyncSpiBus<Word>,
+    // embedded-hal-async has no async `OutputPin` (its `digital` module only
+    // exposes `Wait`); CS is driven synchronously via the blocking trait.
+    CS: OutputPin,
+    D: AsyncDelayNs,
+{
+    #[inline]
+    async fn transaction(
+        &mut self,
+        operations: &mut [Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        let bus = &mut *self.bus.borrow_mut();
+        let asserted = self.cs_asserted();
+        let deasserted = self.cs_deasserted();
+
+        self.cs.set_state(asserted).map_err(DeviceError::Cs)?;
+
+        // Perform the operations, stopping at the first error. We can't use
+        // `try_for_each` here because the closure would need to be `async`.
+        let op_res: Result<(), Self::Error> = 'ops: {
+            for (i, op) in operations.iter_mut().enumerate() {
+                // Pulse CS around each operation boundary if requested.
+                if i != 0 {
+                    if let Some(settle_us) = self.cs_pulse_us {
+                        if let Err(e) = bus.flush().await {
+                            break 'ops Err(DeviceError::Spi(e));
+                        }
+                        if let Err(e) = self.cs.set_state(deasserted) {
+                            break 'ops Err(DeviceError::Cs(e));
+                        }
+                        self.delay.delay_us(settle_us).await;
+                        if let Err(e) = self.cs.set_state(asserted) {
+                            break 'ops Err(DeviceError::Cs(e));
+                        }
+                    }
+                }
+                let res = match op {
+                    Operation::Read(buf) => bus.read(buf).await,
+                    Operation::Write(buf) => bus.write(buf).await,
+                    Operation::Transfer(read, write) => bus.transfer(read, write).await,
+                    Operation::TransferInPlace(buf) => bus.transfer_in_place(buf).await,
+                    Operation::DelayUs(us) => match bus.flush().await {
+                        Err(e) => Err(e),
+                        Ok(()) => {
+                            self.delay.delay_us(*us).await;
+                            Ok(())
+                        }
+                    },
+                };
+                if let Err(e) = res {
+                    break 'ops Err(DeviceError::Spi(e));
+                }
+            }
+            Ok(())
+        };
+
+        // On failure, it's important to still flush and deassert CS.
+        let flush_res = bus.flush().await;
+        let cs_res = self.cs.set_state(deasserted);
 
-        op_res.map_err(DeviceError::Spi)?;
+        op_res?;
         flush_res.map_err(DeviceError::Spi)?;
         cs_res.map_err(DeviceError::Cs)?;
 